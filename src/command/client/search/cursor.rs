@@ -1,11 +1,118 @@
+use std::collections::VecDeque;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Bound on the number of entries a [`KillRing`] keeps, oldest dropped first.
+const KILL_RING_CAPACITY: usize = 16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum KillDirection {
+    Forward,
+    Backward,
+}
+
+/// Emacs-style kill ring: deleted text accumulates here instead of being discarded, so it can be
+/// yanked back. Consecutive kills in the same direction are appended to the most recent entry
+/// rather than creating a new one, matching readline/zle behavior.
+#[derive(Default)]
+struct KillRing {
+    entries: VecDeque<String>,
+    last_kill: Option<KillDirection>,
+    /// The byte range and ring index of the text last inserted by `yank`/`yank_pop`, so a
+    /// following `yank_pop` knows what to replace and which entry to cycle to next.
+    current_yank: Option<(usize, usize, usize)>,
+}
+
+impl KillRing {
+    fn kill(&mut self, text: String, direction: KillDirection) {
+        // An intervening kill command - even a no-op one that deletes nothing - still ends any
+        // in-progress yank-pop chain, so this must run before the early return below.
+        self.current_yank = None;
+        if text.is_empty() {
+            return;
+        }
+        if self.last_kill == Some(direction) {
+            if let Some(top) = self.entries.front_mut() {
+                match direction {
+                    KillDirection::Forward => top.push_str(&text),
+                    KillDirection::Backward => top.insert_str(0, &text),
+                }
+                return;
+            }
+        }
+        self.entries.push_front(text);
+        self.entries.truncate(KILL_RING_CAPACITY);
+        self.last_kill = Some(direction);
+    }
+
+    /// Breaks the kill-sequence chain, called on any non-kill edit/movement so the next kill
+    /// starts a fresh ring entry instead of merging into the previous one.
+    fn note_other_action(&mut self) {
+        self.last_kill = None;
+        self.current_yank = None;
+    }
+
+    /// Returns the most recently killed text, for `yank` to insert.
+    fn front(&self) -> Option<&str> {
+        self.entries.front().map(String::as_str)
+    }
+
+    /// Records the byte range a `yank` just inserted, so a following `yank_pop` knows what to
+    /// replace and starts cycling from the most recent ring entry.
+    fn begin_yank(&mut self, start: usize, end: usize) {
+        self.last_kill = None;
+        self.current_yank = Some((start, end, 0));
+    }
+
+    /// Advances to the next ring entry after the last `yank`/`yank_pop`, returning the byte range
+    /// to replace and the text to replace it with. Returns `None` if there's no yank to cycle.
+    fn cycle_yank(&mut self) -> Option<(usize, usize, String)> {
+        let (start, end, ring_index) = self.current_yank?;
+        if self.entries.is_empty() {
+            return None;
+        }
+        let next_index = (ring_index + 1) % self.entries.len();
+        self.current_yank = Some((start, end, next_index));
+        Some((start, end, self.entries[next_index].clone()))
+    }
+
+    /// Updates the end of the byte range recorded by `begin_yank`/`cycle_yank` once the caller
+    /// knows how long the replacement text turned out to be.
+    fn set_yank_end(&mut self, end: usize) {
+        if let Some((start, _, ring_index)) = self.current_yank {
+            self.current_yank = Some((start, end, ring_index));
+        }
+    }
+}
+
+/// Selects how word motions (`next_word`/`prev_word`) segment `source` into words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WordMode {
+    /// ASCII punctuation/whitespace based segmentation, via [`WORD_SEPARATORS`]. This is the
+    /// default, preserving the cursor's historical behavior.
+    #[default]
+    Ascii,
+    /// Unicode Standard Annex #29 word segmentation, via `unicode-segmentation`. Handles CJK
+    /// text and Unicode punctuation that `Ascii` mode doesn't recognize.
+    Unicode,
+}
+
 pub struct Cursor {
     source: String,
     index: usize,
+    word_mode: WordMode,
+    kill_ring: KillRing,
 }
 
 impl From<String> for Cursor {
     fn from(source: String) -> Self {
-        Self { source, index: 0 }
+        Self {
+            source,
+            index: 0,
+            word_mode: WordMode::default(),
+            kill_ring: KillRing::default(),
+        }
     }
 }
 
@@ -18,37 +125,204 @@ fn is_word_boundary(c: char, next_c: char) -> bool {
         || (!WORD_SEPARATORS.contains(c) && WORD_SEPARATORS.contains(next_c))
 }
 
+// Both of the below walk `source` once via `char_indices`, comparing each char with its
+// predecessor/successor to find a boundary, and report byte offsets throughout so they stay
+// correct for multi-byte input instead of conflating char ordinals with byte indices.
+
 fn get_next_word_pos(source: &str, index: usize) -> usize {
-    let index = (index..source.len().saturating_sub(1)).find(|&i| {
-        is_word_boundary(
-            source.chars().nth(i).unwrap(),
-            source.chars().nth(i + 1).unwrap(),
-        )
-    });
-    if index.is_none() {
+    if index >= source.len() {
+        return source.len();
+    }
+    let mut chars = source.char_indices().skip_while(|&(i, _)| i < index);
+    let Some((_, mut prev)) = chars.next() else {
         return source.len();
+    };
+    let mut boundary = None;
+    for (i, c) in chars {
+        if is_word_boundary(prev, c) {
+            boundary = Some(i);
+            break;
+        }
+        prev = c;
     }
-    (index.unwrap() + 1..source.len())
-        .find(|&i| !source.chars().nth(i).unwrap().is_whitespace())
-        .unwrap_or(source.len())
+    let Some(boundary) = boundary else {
+        return source.len();
+    };
+    source[boundary..]
+        .char_indices()
+        .find(|&(_, c)| !c.is_whitespace())
+        .map_or(source.len(), |(i, _)| boundary + i)
 }
 
 fn get_prev_word_pos(source: &str, index: usize) -> usize {
-    let index = (1..index)
-        .rev()
-        .find(|&i| !source.chars().nth(i).unwrap().is_whitespace());
-    if index.is_none() {
+    if index == 0 {
+        return 0;
+    }
+    let mut chars = source[..index].char_indices().rev().peekable();
+    while matches!(chars.peek(), Some((_, c)) if c.is_whitespace()) {
+        chars.next();
+    }
+    let Some((mut boundary, mut next)) = chars.next() else {
+        return 0;
+    };
+    for (i, c) in chars {
+        if is_word_boundary(c, next) {
+            break;
+        }
+        boundary = i;
+        next = c;
+    }
+    boundary
+}
+
+// Unicode-aware counterparts of the above, segmenting `source` into UAX #29 words via
+// `split_word_bound_indices` instead of the ASCII `WORD_SEPARATORS` set.
+
+fn get_next_word_pos_unicode(source: &str, index: usize) -> usize {
+    let tokens: Vec<(usize, &str)> = source.split_word_bound_indices().collect();
+    let Some(pos) = tokens
+        .iter()
+        .position(|&(i, t)| i <= index && index < i + t.len())
+    else {
+        return source.len();
+    };
+    tokens[pos + 1..]
+        .iter()
+        .find(|(_, t)| !t.trim().is_empty())
+        .map_or(source.len(), |&(i, _)| i)
+}
+
+fn get_prev_word_pos_unicode(source: &str, index: usize) -> usize {
+    if index == 0 {
         return 0;
     }
-    (1..index.unwrap())
+    let tokens: Vec<(usize, &str)> = source.split_word_bound_indices().collect();
+    let Some(pos) = tokens.iter().rposition(|&(i, _)| i < index) else {
+        return 0;
+    };
+    tokens[..=pos]
+        .iter()
         .rev()
-        .find(|&i| {
-            is_word_boundary(
-                source.chars().nth(i - 1).unwrap(),
-                source.chars().nth(i).unwrap(),
-            )
-        })
-        .unwrap_or(0)
+        .find(|(_, t)| !t.trim().is_empty())
+        .map_or(0, |&(i, _)| i)
+}
+
+/// The kind of a [`TokenSpan`] produced by [`scan_shell_tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A run of unquoted ordinary characters. A backslash escape (such as `\ `) is kept inside
+    /// the word rather than splitting it.
+    Word,
+    /// A `'single'`/`"double"` quoted span, a backtick span, or a `$(...)` command
+    /// substitution - traversed and deleted as one unit.
+    Quoted,
+    /// A shell metacharacter: `| & ; < > ( )`.
+    Operator,
+}
+
+/// A classified, contiguous byte range of a shell command line, as produced by
+/// [`Cursor::tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenSpan {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+static SHELL_OPERATORS: &str = "|&;<>()";
+
+/// Left-to-right shell tokenizer: tracks quote/escape state so quoted spans, backslash escapes,
+/// and `$(...)`/backtick command substitutions are each reported as a single [`TokenSpan`].
+fn scan_shell_tokens(source: &str) -> Vec<TokenSpan> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let len = chars.len();
+    let char_end = |idx: usize| chars.get(idx).map_or(source.len(), |&(i, _)| i);
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < len {
+        let (start, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '\'' {
+            i += 1;
+            while i < len && chars[i].1 != '\'' {
+                i += 1;
+            }
+            i = (i + 1).min(len);
+            tokens.push(TokenSpan {
+                kind: TokenKind::Quoted,
+                start,
+                end: char_end(i),
+            });
+            continue;
+        }
+        if c == '"' || c == '`' {
+            let quote = c;
+            i += 1;
+            while i < len && chars[i].1 != quote {
+                i += if chars[i].1 == '\\' && i + 1 < len {
+                    2
+                } else {
+                    1
+                };
+            }
+            i = (i + 1).min(len);
+            tokens.push(TokenSpan {
+                kind: TokenKind::Quoted,
+                start,
+                end: char_end(i),
+            });
+            continue;
+        }
+        if c == '$' && chars.get(i + 1).map(|&(_, c)| c) == Some('(') {
+            i += 2;
+            let mut depth = 1;
+            while i < len && depth > 0 {
+                match chars[i].1 {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    '\\' if i + 1 < len => i += 1,
+                    _ => {}
+                }
+                i += 1;
+            }
+            tokens.push(TokenSpan {
+                kind: TokenKind::Quoted,
+                start,
+                end: char_end(i),
+            });
+            continue;
+        }
+        if SHELL_OPERATORS.contains(c) {
+            i += 1;
+            tokens.push(TokenSpan {
+                kind: TokenKind::Operator,
+                start,
+                end: char_end(i),
+            });
+            continue;
+        }
+        while i < len {
+            let c = chars[i].1;
+            if c.is_whitespace() || SHELL_OPERATORS.contains(c) || c == '\'' || c == '"' || c == '`'
+            {
+                break;
+            }
+            if c == '$' && chars.get(i + 1).map(|&(_, c)| c) == Some('(') {
+                break;
+            }
+            i += if c == '\\' && i + 1 < len { 2 } else { 1 };
+        }
+        tokens.push(TokenSpan {
+            kind: TokenKind::Word,
+            start,
+            end: char_end(i),
+        });
+    }
+    tokens
 }
 
 impl Cursor {
@@ -65,68 +339,205 @@ impl Cursor {
         &self.source[..self.index]
     }
 
-    /// Returns the currently selected [`char`]
-    pub fn char(&self) -> Option<char> {
-        self.source[self.index..].chars().next()
+    /// Returns the total on-screen width of the line in terminal columns, treating combining
+    /// marks as zero-width and East Asian wide/fullwidth characters as two columns.
+    pub fn display_width(&self) -> usize {
+        self.source.width()
     }
 
+    /// Returns the on-screen width, in terminal columns, of the line up to the cursor. Use this
+    /// (rather than `substring().len()` or a char count) to place the terminal cursor.
+    pub fn cursor_column(&self) -> usize {
+        self.substring().width()
+    }
+
+    /// Returns the grapheme cluster under the cursor
+    pub fn char(&self) -> Option<&str> {
+        self.source[self.index..].graphemes(true).next()
+    }
+
+    /// Moves the cursor one grapheme cluster to the right
     pub fn right(&mut self) {
-        if self.index < self.source.len() {
-            loop {
-                self.index += 1;
-                if self.source.is_char_boundary(self.index) {
-                    break;
-                }
-            }
+        self.kill_ring.note_other_action();
+        if let Some(g) = self.char() {
+            self.index += g.len();
         }
     }
 
+    /// Moves the cursor one grapheme cluster to the left
     pub fn left(&mut self) -> bool {
-        if self.index > 0 {
-            loop {
-                self.index -= 1;
-                if self.source.is_char_boundary(self.index) {
-                    break true;
-                }
-            }
-        } else {
-            false
+        self.kill_ring.note_other_action();
+        if self.index == 0 {
+            return false;
+        }
+        let prev_len = self.source[..self.index]
+            .graphemes(true)
+            .next_back()
+            .map(str::len)
+            .unwrap_or(0);
+        self.index -= prev_len;
+        true
+    }
+
+    /// Sets how word motions segment the line into words.
+    pub fn set_word_mode(&mut self, mode: WordMode) {
+        self.word_mode = mode;
+    }
+
+    fn next_word_pos(&self) -> usize {
+        match self.word_mode {
+            WordMode::Ascii => get_next_word_pos(&self.source, self.index),
+            WordMode::Unicode => get_next_word_pos_unicode(&self.source, self.index),
+        }
+    }
+
+    fn prev_word_pos(&self) -> usize {
+        match self.word_mode {
+            WordMode::Ascii => get_prev_word_pos(&self.source, self.index),
+            WordMode::Unicode => get_prev_word_pos_unicode(&self.source, self.index),
         }
     }
 
     pub fn next_word(&mut self) {
-        self.index = get_next_word_pos(&self.source, self.index);
+        self.kill_ring.note_other_action();
+        self.index = self.next_word_pos();
     }
 
     pub fn prev_word(&mut self) {
-        self.index = get_prev_word_pos(&self.source, self.index);
+        self.kill_ring.note_other_action();
+        self.index = self.prev_word_pos();
+    }
+
+    /// Returns the line tokenized into shell words, quoted spans, and operators. Higher layers
+    /// (completion, syntax highlighting) can consume the same spans as the motions below.
+    pub fn tokens(&self) -> Vec<TokenSpan> {
+        scan_shell_tokens(&self.source)
+    }
+
+    fn next_shell_word_pos(&self) -> usize {
+        let tokens = self.tokens();
+        match tokens
+            .iter()
+            .position(|t| t.start <= self.index && self.index < t.end)
+        {
+            Some(pos) => tokens.get(pos + 1).map_or(self.source.len(), |t| t.start),
+            None => tokens
+                .iter()
+                .find(|t| t.start > self.index)
+                .map_or(self.source.len(), |t| t.start),
+        }
+    }
+
+    fn prev_shell_word_pos(&self) -> usize {
+        let tokens = self.tokens();
+        tokens
+            .iter()
+            .rposition(|t| t.start < self.index)
+            .map_or(0, |pos| tokens[pos].start)
+    }
+
+    /// Moves to the start of the next shell word, honoring quoting/escaping/substitution
+    pub fn next_shell_word(&mut self) {
+        self.kill_ring.note_other_action();
+        self.index = self.next_shell_word_pos();
+    }
+
+    /// Moves to the start of the current (or previous) shell word, honoring
+    /// quoting/escaping/substitution
+    pub fn prev_shell_word(&mut self) {
+        self.kill_ring.note_other_action();
+        self.index = self.prev_shell_word_pos();
+    }
+
+    /// Deletes the shell word under the cursor as a single unit, pushing it onto the kill ring
+    pub fn remove_shell_word(&mut self) {
+        let token = self
+            .tokens()
+            .into_iter()
+            .find(|t| t.start <= self.index && self.index < t.end);
+        let killed = match token {
+            Some(token) => {
+                let killed = self.source[token.start..token.end].to_string();
+                self.source.replace_range(token.start..token.end, "");
+                self.index = token.start;
+                killed
+            }
+            None => String::new(),
+        };
+        // Even a no-op (no token under the cursor) must still end an in-progress yank-pop
+        // chain, so this always routes through `kill`, which clears it unconditionally.
+        self.kill_ring.kill(killed, KillDirection::Forward);
     }
 
     pub fn insert(&mut self, c: char) {
+        self.kill_ring.note_other_action();
         self.source.insert(self.index, c);
         self.index += c.len_utf8();
     }
 
-    pub fn remove(&mut self) -> Option<char> {
-        if self.index < self.source.len() {
-            Some(self.source.remove(self.index))
-        } else {
-            None
-        }
+    /// Removes the grapheme cluster under the cursor
+    pub fn remove(&mut self) -> Option<String> {
+        self.kill_ring.note_other_action();
+        let g = self.char()?.to_string();
+        self.source
+            .replace_range(self.index..self.index + g.len(), "");
+        Some(g)
     }
 
+    /// Deletes the next word, pushing it onto the kill ring
     pub fn remove_next_word(&mut self) {
-        let next_index = get_next_word_pos(&self.source, self.index);
+        let next_index = self.next_word_pos();
+        let killed = self.source[self.index..next_index].to_string();
         self.source.replace_range(self.index..next_index, "");
+        self.kill_ring.kill(killed, KillDirection::Forward);
     }
 
+    /// Deletes the previous word, pushing it onto the kill ring
     pub fn remove_prev_word(&mut self) {
-        let next_index = get_prev_word_pos(&self.source, self.index);
+        let next_index = self.prev_word_pos();
+        let killed = self.source[next_index..self.index].to_string();
         self.source.replace_range(next_index..self.index, "");
         self.index = next_index;
+        self.kill_ring.kill(killed, KillDirection::Backward);
     }
 
-    pub fn back(&mut self) -> Option<char> {
+    /// Deletes from the cursor to the end of the line, pushing it onto the kill ring
+    pub fn kill_line(&mut self) {
+        let killed = self.source[self.index..].to_string();
+        self.source.truncate(self.index);
+        self.kill_ring.kill(killed, KillDirection::Forward);
+    }
+
+    /// Deletes the whole line, pushing it onto the kill ring
+    pub fn kill_whole_line(&mut self) {
+        let killed = std::mem::take(&mut self.source);
+        self.index = 0;
+        self.kill_ring.kill(killed, KillDirection::Forward);
+    }
+
+    /// Inserts the most recently killed text at the cursor
+    pub fn yank(&mut self) {
+        let Some(text) = self.kill_ring.front().map(str::to_string) else {
+            return;
+        };
+        let start = self.index;
+        self.source.insert_str(start, &text);
+        self.index = start + text.len();
+        self.kill_ring.begin_yank(start, self.index);
+    }
+
+    /// Replaces the text inserted by the last `yank`/`yank_pop` with the previous kill-ring entry
+    pub fn yank_pop(&mut self) {
+        let Some((start, end, text)) = self.kill_ring.cycle_yank() else {
+            return;
+        };
+        self.source.replace_range(start..end, &text);
+        self.index = start + text.len();
+        self.kill_ring.set_yank_end(self.index);
+    }
+
+    /// Moves one grapheme cluster left and removes it
+    pub fn back(&mut self) -> Option<String> {
         if self.left() {
             self.remove()
         } else {
@@ -135,15 +546,18 @@ impl Cursor {
     }
 
     pub fn clear(&mut self) {
+        self.kill_ring.note_other_action();
         self.source.clear();
         self.index = 0;
     }
 
     pub fn end(&mut self) {
+        self.kill_ring.note_other_action();
         self.index = self.source.len();
     }
 
     pub fn start(&mut self) {
+        self.kill_ring.note_other_action();
         self.index = 0;
     }
 }
@@ -196,6 +610,228 @@ mod cursor_tests {
         assert_eq!(get_prev_word_pos("", 0), 0);
     }
 
+    #[test]
+    fn test_get_next_word_pos_multibyte() {
+        // "café" and "naïve" each contain a 2-byte character, so byte offsets diverge
+        // from char counts; the separator "/" is its own single-char word.
+        let s = "café/naïve";
+        assert_eq!(get_next_word_pos(s, 0), 5);
+        assert_eq!(get_next_word_pos(s, 5), 6);
+    }
+
+    #[test]
+    fn test_get_prev_word_pos_multibyte() {
+        let s = "café/naïve";
+        assert_eq!(get_prev_word_pos(s, s.len()), 6);
+        assert_eq!(get_prev_word_pos(s, 6), 5);
+        assert_eq!(get_prev_word_pos(s, 5), 0);
+    }
+
+    #[test]
+    fn word_mode_ascii_keeps_legacy_behavior() {
+        // em dash isn't an ASCII separator, so Ascii mode sees one long word
+        let mut c = Cursor::from(String::from("foo—bar"));
+        c.next_word();
+        assert_eq!(c.index, "foo—bar".len());
+    }
+
+    #[test]
+    fn word_mode_unicode_segments_on_punctuation() {
+        let s = String::from("foo—bar");
+        let mut c = Cursor::from(s.clone());
+        c.set_word_mode(WordMode::Unicode);
+        c.next_word();
+        assert_eq!(c.index, "foo".len());
+
+        let mut c = Cursor::from(s);
+        c.set_word_mode(WordMode::Unicode);
+        c.end();
+        c.prev_word();
+        assert_eq!(c.index, "foo—".len());
+    }
+
+    #[test]
+    fn word_mode_unicode_segments_cjk_by_ideograph() {
+        // UAX #29 has no dictionary, so each CJK ideograph is its own word segment.
+        let mut c = Cursor::from(String::from("你好"));
+        c.set_word_mode(WordMode::Unicode);
+        c.next_word();
+        assert_eq!(c.substring(), "你");
+        c.next_word();
+        assert_eq!(c.substring(), "你好");
+    }
+
+    #[test]
+    fn display_width_and_cursor_column() {
+        // "中" is a fullwidth CJK ideograph: 2 columns
+        let mut c = Cursor::from(String::from("a中b"));
+        assert_eq!(c.display_width(), 4);
+        assert_eq!(c.cursor_column(), 0);
+        c.right();
+        assert_eq!(c.cursor_column(), 1);
+        c.right();
+        assert_eq!(c.cursor_column(), 3);
+    }
+
+    #[test]
+    fn display_width_ignores_combining_marks() {
+        // "e" followed by a combining acute accent renders as one column, not two
+        let combining_e_acute = "e\u{0301}";
+        let c = Cursor::from(format!("{combining_e_acute}x"));
+        assert_eq!(c.display_width(), 2);
+    }
+
+    #[test]
+    fn kill_ring_appends_on_repeated_forward_kills() {
+        let mut c = Cursor::from(String::from("foo bar baz"));
+        c.remove_next_word(); // kills "foo "
+        c.remove_next_word(); // kills "bar "
+        assert_eq!(c.as_str(), "baz");
+        c.end();
+        c.yank();
+        assert_eq!(c.as_str(), "bazfoo bar ");
+    }
+
+    #[test]
+    fn kill_ring_does_not_append_across_other_actions() {
+        let mut c = Cursor::from(String::from("foo bar"));
+        c.remove_next_word(); // kills "foo "
+        c.right(); // breaks the kill chain
+        c.left();
+        c.start();
+        c.remove_next_word(); // kills "bar", a fresh entry
+        c.end();
+        c.yank();
+        assert_eq!(c.as_str(), "bar");
+        c.yank_pop();
+        assert_eq!(c.as_str(), "foo ");
+    }
+
+    #[test]
+    fn kill_line_and_kill_whole_line() {
+        let mut c = Cursor::from(String::from("foo bar"));
+        for _ in 0..4 {
+            c.right();
+        }
+        c.kill_line();
+        assert_eq!(c.as_str(), "foo ");
+
+        let mut c = Cursor::from(String::from("foo bar"));
+        c.right();
+        c.kill_whole_line();
+        assert_eq!(c.as_str(), "");
+        assert_eq!(c.index, 0);
+        c.yank();
+        assert_eq!(c.as_str(), "foo bar");
+    }
+
+    #[test]
+    fn yank_pop_cycles_through_ring() {
+        let mut c = Cursor::from(String::from("aaa bbb"));
+        c.remove_next_word(); // kills "aaa ", entry 0
+        c.right();
+        c.start();
+        c.remove_next_word(); // kills "bbb", entry 0 (pushes "aaa " to entry 1)
+        c.end();
+        c.yank();
+        assert_eq!(c.as_str(), "bbb");
+        c.yank_pop();
+        assert_eq!(c.as_str(), "aaa ");
+        // only two entries in the ring, so popping again cycles back to the first
+        c.yank_pop();
+        assert_eq!(c.as_str(), "bbb");
+    }
+
+    #[test]
+    fn tokens_classifies_quotes_operators_and_substitution() {
+        let c = Cursor::from(String::from("echo \"a b c\" | cat"));
+        let kinds: Vec<_> = c
+            .tokens()
+            .into_iter()
+            .map(|t| (t.kind, t.start, t.end))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (TokenKind::Word, 0, 4),
+                (TokenKind::Quoted, 5, 12),
+                (TokenKind::Operator, 13, 14),
+                (TokenKind::Word, 15, 18),
+            ]
+        );
+
+        let c = Cursor::from(String::from("echo $(ls -l) rest"));
+        let kinds: Vec<_> = c
+            .tokens()
+            .into_iter()
+            .map(|t| (t.kind, t.start, t.end))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (TokenKind::Word, 0, 4),
+                (TokenKind::Quoted, 5, 13),
+                (TokenKind::Word, 14, 18),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_treats_backslash_escape_as_part_of_the_word() {
+        // the escaped space doesn't split "a\ b" into two words
+        let c = Cursor::from(String::from("a\\ b"));
+        let kinds: Vec<_> = c
+            .tokens()
+            .into_iter()
+            .map(|t| (t.kind, t.start, t.end))
+            .collect();
+        assert_eq!(kinds, vec![(TokenKind::Word, 0, 4)]);
+    }
+
+    #[test]
+    fn shell_word_motions_jump_over_whole_quoted_spans() {
+        let mut c = Cursor::from(String::from("echo \"a b c\" | cat"));
+        c.next_shell_word();
+        assert_eq!(c.index, 5); // start of the quoted argument
+        c.next_shell_word();
+        assert_eq!(c.index, 13); // start of "|"
+        c.next_shell_word();
+        assert_eq!(c.index, 15); // start of "cat"
+
+        c.prev_shell_word();
+        assert_eq!(c.index, 13);
+        c.prev_shell_word();
+        assert_eq!(c.index, 5);
+        c.prev_shell_word();
+        assert_eq!(c.index, 0);
+    }
+
+    #[test]
+    fn remove_shell_word_deletes_quoted_span_as_one_unit() {
+        let mut c = Cursor::from(String::from("echo \"a b c\" | cat"));
+        c.next_shell_word();
+        assert_eq!(c.index, 5);
+        c.remove_shell_word();
+        assert_eq!(c.as_str(), "echo  | cat");
+        assert_eq!(c.index, 5);
+    }
+
+    #[test]
+    fn remove_shell_word_no_op_still_ends_yank_pop_chain() {
+        let mut c = Cursor::from(String::from("aaa bbb"));
+        c.remove_next_word(); // kills "aaa "
+        c.right();
+        c.start();
+        c.remove_next_word(); // kills "bbb", a fresh entry
+        c.end();
+        c.yank(); // pastes "bbb", cursor now at the end, starting a yank-pop chain
+        assert_eq!(c.as_str(), "bbb");
+        c.remove_shell_word(); // no token under the cursor: a no-op, but still ends the chain
+        assert_eq!(c.as_str(), "bbb");
+        c.yank_pop(); // should do nothing: the chain was already broken
+        assert_eq!(c.as_str(), "bbb");
+    }
+
     #[test]
     fn pop() {
         let mut s = String::from("öaöböcödöeöfö");
@@ -204,12 +840,12 @@ mod cursor_tests {
         while !s.is_empty() {
             let c1 = s.pop();
             let c2 = c.back();
-            assert_eq!(c1, c2);
+            assert_eq!(c2, c1.map(|c| c.to_string()));
             assert_eq!(s.as_str(), c.substring());
         }
         let c1 = s.pop();
         let c2 = c.back();
-        assert_eq!(c1, c2);
+        assert_eq!(c2, c1.map(|c| c.to_string()));
     }
 
     #[test]
@@ -220,10 +856,10 @@ mod cursor_tests {
             c.right();
         }
         assert_eq!(c.substring(), "öaöb");
-        assert_eq!(c.back(), Some('b'));
-        assert_eq!(c.back(), Some('ö'));
-        assert_eq!(c.back(), Some('a'));
-        assert_eq!(c.back(), Some('ö'));
+        assert_eq!(c.back(), Some("b".to_string()));
+        assert_eq!(c.back(), Some("ö".to_string()));
+        assert_eq!(c.back(), Some("a".to_string()));
+        assert_eq!(c.back(), Some("ö".to_string()));
         assert_eq!(c.back(), None);
         assert_eq!(c.as_str(), "öcödöeöfö");
     }
@@ -243,4 +879,34 @@ mod cursor_tests {
         assert_eq!(c.substring(), "öaöbögöh");
         assert_eq!(c.as_str(), "öaöbögöhöcödöeöfö");
     }
+
+    #[test]
+    fn right_left_grapheme_cluster() {
+        // family emoji: a single user-perceived character made of 4 codepoints joined by ZWJ
+        let family = "👨‍👩‍👧‍👦";
+        let mut c = Cursor::from(format!("a{family}b"));
+        c.right();
+        assert_eq!(c.index, 1);
+        c.right();
+        assert_eq!(c.index, 1 + family.len());
+        c.left();
+        assert_eq!(c.index, 1);
+    }
+
+    #[test]
+    fn char_returns_whole_grapheme_cluster() {
+        // "e" followed by a combining acute accent is one grapheme cluster, two chars
+        let combining_e_acute = "e\u{0301}";
+        let c = Cursor::from(format!("{combining_e_acute}x"));
+        assert_eq!(c.char(), Some(combining_e_acute));
+    }
+
+    #[test]
+    fn back_removes_whole_grapheme_cluster() {
+        let family = "👨‍👩‍👧‍👦";
+        let mut c = Cursor::from(format!("a{family}"));
+        c.end();
+        assert_eq!(c.back(), Some(family.to_string()));
+        assert_eq!(c.as_str(), "a");
+    }
 }